@@ -10,6 +10,10 @@ pub struct InitMsg {
     pub entropy: String,
     /// auction contract info
     pub auction_contract: AuctionContractInfo,
+    /// protocol fee configuration
+    pub fee_info: FeeInfo,
+    /// auction lifetime and anti-sniping rules
+    pub auction_rules: AuctionRules,
 }
 
 /// Handle messages
@@ -26,16 +30,39 @@ pub enum HandleMsg {
         bid_contract: ContractInfo,
         /// amount of tokens being sold
         sell_amount: Uint128,
-        /// minimum bid that will be accepted
+        /// minimum bid that will be accepted.  For a Descending auction this is the start_price,
+        /// and is rejected if its premium over floor_price exceeds the factory's max_premium_bps.
+        /// Also rejected if it is not a multiple of the pair's min_bid_increment or falls below
+        /// its min_notional, when PairFilters have been set for this SELL-BID pair
         minimum_bid: Uint128,
         /// timestamp after which anyone may close the auction.
-        /// Timestamp is in seconds since epoch 01/01/1970
+        /// Timestamp is in seconds since epoch 01/01/1970.  Rejected if it does not give the
+        /// auction at least the factory's min_lifetime_seconds
         ends_at: u64,
         /// Optional free-form description of the auction (best to avoid double quotes). As an example
         /// it could be the date the owner will likely finalize the auction, or a list of other
         /// auctions for the same token, etc...
         #[serde(default)]
         description: Option<String>,
+        /// pricing mode for this auction.  Defaults to Sealed if not specified
+        #[serde(default)]
+        auction_type: Option<AuctionType>,
+        /// starting price of a Descending auction, before any discount pool has been applied
+        #[serde(default)]
+        start_price: Option<Uint128>,
+        /// floor price of a Descending auction, below which the effective minimum bid will not fall
+        #[serde(default)]
+        floor_price: Option<Uint128>,
+        /// number of discount pools the price steps through between start_price and floor_price
+        #[serde(default)]
+        pool_count: Option<u16>,
+        /// number of seconds each discount pool remains active before stepping down to the next
+        #[serde(default)]
+        interval_seconds: Option<u64>,
+        /// optional price that, once bid, closes the auction immediately instead of waiting for
+        /// ends_at
+        #[serde(default)]
+        buy_now_price: Option<Uint128>,
     },
 
     /// RegisterAuction saves the auction info of a newly instantiated auction and adds it to the list
@@ -65,6 +92,9 @@ pub enum HandleMsg {
         /// winning bid if the auction ended in a swap
         #[serde(default)]
         winning_bid: Option<Uint128>,
+        /// how the auction came to a close.  Defaults to Timeout if not specified
+        #[serde(default)]
+        closed_by: Option<CloseReason>,
     },
 
     /// RegisterBidder allows the factory to know an auction has a new bidder so it can update their
@@ -93,6 +123,30 @@ pub enum HandleMsg {
         auction_contract: AuctionContractInfo,
     },
 
+    /// Allows the admin to update the protocol fee configuration
+    SetFees {
+        /// new protocol fee configuration
+        fee_info: FeeInfo,
+    },
+
+    /// Allows the admin to update the auction lifetime and anti-sniping rules
+    SetAuctionRules {
+        /// new auction rules
+        auction_rules: AuctionRules,
+    },
+
+    /// Allows the admin to set the tick size and minimum notional for a SELL-BID pair
+    SetPairFilters {
+        /// sell symbol index
+        sell_symbol: u16,
+        /// bid symbol index
+        bid_symbol: u16,
+        /// minimum bid increment; CreateAuction's minimum_bid must be an exact multiple of this
+        min_bid_increment: Uint128,
+        /// minimum notional value CreateAuction's minimum_bid must meet or exceed
+        min_notional: Uint128,
+    },
+
     /// Create a viewing key to be used with all factory and auction authenticated queries
     CreateViewingKey { entropy: String },
 
@@ -141,7 +195,9 @@ pub enum QueryMsg {
     /// only that number of auctions (default is 200).  If you specify the before parameter, it will
     /// start listing from the first auction whose index is less than "before".  If you are
     /// paginating, you would take the index of the last auction you receive, and specify that as the
-    /// before parameter on your next query so it will continue where it left off
+    /// before parameter on your next query so it will continue where it left off.  You may also
+    /// narrow the results with from/to timestamps, a pair filter, and/or a min_winning_bid floor;
+    /// these are applied server-side before before/page_size paging
     ListClosedAuctions {
         /// optionally only show auctions with index less than specified value
         #[serde(default)]
@@ -149,6 +205,20 @@ pub enum QueryMsg {
         /// optional number of auctions to return
         #[serde(default)]
         page_size: Option<u32>,
+        /// optionally only show auctions that closed at or after this timestamp (seconds since
+        /// epoch 01/01/1970)
+        #[serde(default)]
+        from: Option<u64>,
+        /// optionally only show auctions that closed at or before this timestamp (seconds since
+        /// epoch 01/01/1970)
+        #[serde(default)]
+        to: Option<u64>,
+        /// optionally only show auctions for this SELL-BID token pair
+        #[serde(default)]
+        pair: Option<String>,
+        /// optionally only show auctions whose winning bid was at least this amount
+        #[serde(default)]
+        min_winning_bid: Option<Uint128>,
     },
     /// authenticates the supplied address/viewing key.  This should only be called by auctions
     IsKeyValid {
@@ -157,6 +227,13 @@ pub enum QueryMsg {
         /// viewing key
         viewing_key: String,
     },
+    /// gets the active tick size and minimum notional filters for a SELL-BID pair
+    PairFilters {
+        /// sell symbol index
+        sell_symbol: u16,
+        /// bid symbol index
+        bid_symbol: u16,
+    },
 }
 
 /// the filter types when viewing an address' auctions
@@ -197,6 +274,12 @@ pub enum QueryAnswer {
     ViewingKeyError { error: String },
     /// result of authenticating address/key pair
     IsKeyValid { is_valid: bool },
+    /// the active tick size and minimum notional filters for a SELL-BID pair
+    PairFilters {
+        /// None if no filters have been set for this pair
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filters: Option<PairFilters>,
+    },
 }
 
 /// Lists of active auctions sorted by pair where the address is a seller or bidder
@@ -265,6 +348,118 @@ pub struct AuctionContractInfo {
     pub code_hash: String,
 }
 
+/// protocol fee configuration
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct FeeInfo {
+    /// fee charged on the winning bid when an auction closes, in basis points (1/100th of a percent)
+    pub bid_fee_bps: u16,
+    /// maximum premium rate, in basis points, a Descending auction's start_price may sit above
+    /// its floor_price
+    pub max_premium_bps: u16,
+    /// address that receives the fee collected from closed auctions
+    pub fee_collector: HumanAddr,
+}
+
+impl FeeInfo {
+    /// splits a winning bid into the protocol fee and the amount the seller actually receives
+    pub fn split(&self, winning_bid: u128) -> (u128, u128) {
+        let fee_paid = winning_bid.saturating_mul(u128::from(self.bid_fee_bps)) / 10_000;
+        let net_to_seller = winning_bid.saturating_sub(fee_paid);
+        (fee_paid, net_to_seller)
+    }
+
+    /// returns true if a Descending auction's start_price does not carry a premium over
+    /// floor_price greater than max_premium_bps
+    pub fn premium_allowed(&self, start_price: u128, floor_price: u128) -> bool {
+        if floor_price == 0 {
+            return false;
+        }
+        let premium_bps = start_price.saturating_sub(floor_price).saturating_mul(10_000) / floor_price;
+        premium_bps <= u128::from(self.max_premium_bps)
+    }
+}
+
+/// auction lifetime and anti-sniping rules
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct AuctionRules {
+    /// the shortest lifetime, in seconds, CreateAuction will accept between now and ends_at
+    pub min_lifetime_seconds: u64,
+    /// if a bid is registered within this many seconds of ends_at, ends_at is pushed out by
+    /// snipe_extension_seconds
+    pub snipe_window_seconds: u64,
+    /// how far, in seconds, to push ends_at out when a bid lands inside snipe_window_seconds
+    pub snipe_extension_seconds: u64,
+}
+
+impl AuctionRules {
+    /// returns true if the span between created_at and ends_at meets min_lifetime_seconds
+    pub fn meets_min_lifetime(&self, created_at: u64, ends_at: u64) -> bool {
+        ends_at.saturating_sub(created_at) >= self.min_lifetime_seconds
+    }
+
+    /// returns the new ends_at if a bid registered at bid_at falls within snipe_window_seconds
+    /// of the current ends_at, or None if the auction should not be extended
+    pub fn extend_for_snipe(&self, ends_at: u64, bid_at: u64) -> Option<u64> {
+        if bid_at < ends_at && ends_at - bid_at <= self.snipe_window_seconds {
+            Some(ends_at + self.snipe_extension_seconds)
+        } else {
+            None
+        }
+    }
+}
+
+/// tick size and minimum notional trading rules for a SELL-BID pair
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct PairFilters {
+    /// minimum bid increment; CreateAuction's minimum_bid must be an exact multiple of this
+    pub min_bid_increment: Uint128,
+    /// minimum notional value CreateAuction's minimum_bid must meet or exceed
+    pub min_notional: Uint128,
+}
+
+impl PairFilters {
+    /// returns true if minimum_bid is a valid multiple of min_bid_increment and meets
+    /// min_notional
+    pub fn allows(&self, minimum_bid: u128) -> bool {
+        let increment = self.min_bid_increment.u128();
+        increment > 0 && minimum_bid % increment == 0 && minimum_bid >= self.min_notional.u128()
+    }
+}
+
+/// auction pricing mode
+#[derive(Serialize, Deserialize, JsonSchema, PartialEq, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum AuctionType {
+    /// a single sealed-bid auction with one fixed minimum_bid
+    Sealed,
+    /// price steps down from start_price to floor_price one discount pool at a time
+    Descending,
+}
+
+impl Default for AuctionType {
+    fn default() -> Self {
+        AuctionType::Sealed
+    }
+}
+
+/// reason an auction closed
+#[derive(Serialize, Deserialize, JsonSchema, PartialEq, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum CloseReason {
+    /// the auction closed because ends_at was reached
+    Timeout,
+    /// the auction closed because a bid met or exceeded buy_now_price
+    BuyNow,
+    /// the seller closed the auction before ends_at
+    SellerClosed,
+}
+
+impl Default for CloseReason {
+    fn default() -> Self {
+        CloseReason::Timeout
+    }
+}
+
 /// active auction display info
 #[derive(Serialize, Deserialize, JsonSchema)]
 pub struct AuctionInfo {
@@ -278,13 +473,17 @@ pub struct AuctionInfo {
     pub sell_amount: Uint128,
     /// number of decimal places in sell_amount
     pub sell_decimals: u8,
-    /// minimum bid
+    /// current effective minimum bid.  For a Descending auction this is the price of the
+    /// discount pool that is active right now; for a Sealed auction it is the fixed minimum_bid
     pub minimum_bid: Uint128,
     /// number of decimal places in minimum_bid
     pub bid_decimals: u8,
     /// timestamp after which anyone may close the auction.
     /// Timestamp is in seconds since epoch 01/01/1970
     pub ends_at: u64,
+    /// optional price that, once bid, closes the auction immediately
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buy_now_price: Option<Uint128>,
 }
 
 /// active auction info for storage
@@ -302,9 +501,30 @@ pub struct RegisterAuctionInfo {
     pub sell_amount: Uint128,
     /// minimum bid
     pub minimum_bid: Uint128,
+    /// timestamp the auction was instantiated, in seconds since epoch 01/01/1970.  Anchors the
+    /// discount pool schedule of a Descending auction
+    pub created_at: u64,
     /// timestamp after which anyone may close the auction.
     /// Timestamp is in seconds since epoch 01/01/1970
     pub ends_at: u64,
+    /// pricing mode for this auction.  Defaults to Sealed if not specified
+    #[serde(default)]
+    pub auction_type: Option<AuctionType>,
+    /// starting price of a Descending auction, before any discount pool has been applied
+    #[serde(default)]
+    pub start_price: Option<Uint128>,
+    /// floor price of a Descending auction, below which the effective minimum bid will not fall
+    #[serde(default)]
+    pub floor_price: Option<Uint128>,
+    /// number of discount pools the price steps through between start_price and floor_price
+    #[serde(default)]
+    pub pool_count: Option<u16>,
+    /// number of seconds each discount pool remains active before stepping down to the next
+    #[serde(default)]
+    pub interval_seconds: Option<u64>,
+    /// optional price that, once bid, closes the auction immediately
+    #[serde(default)]
+    pub buy_now_price: Option<Uint128>,
 }
 
 impl RegisterAuctionInfo {
@@ -317,7 +537,14 @@ impl RegisterAuctionInfo {
             bid_symbol: self.bid_symbol,
             sell_amount: self.sell_amount.u128(),
             minimum_bid: self.minimum_bid.u128(),
+            created_at: self.created_at,
             ends_at: self.ends_at,
+            auction_type: self.auction_type.unwrap_or_default(),
+            start_price: self.start_price.map(Uint128::u128),
+            floor_price: self.floor_price.map(Uint128::u128),
+            pool_count: self.pool_count,
+            interval_seconds: self.interval_seconds,
+            buy_now_price: self.buy_now_price.map(Uint128::u128),
         }
     }
 }
@@ -337,18 +564,80 @@ pub struct StoreAuctionInfo {
     pub sell_amount: u128,
     /// minimum bid
     pub minimum_bid: u128,
+    /// timestamp the auction was instantiated, in seconds since epoch 01/01/1970.  Anchors the
+    /// discount pool schedule of a Descending auction
+    pub created_at: u64,
     /// timestamp after which anyone may close the auction.
     /// Timestamp is in seconds since epoch 01/01/1970
     pub ends_at: u64,
+    /// pricing mode for this auction
+    pub auction_type: AuctionType,
+    /// starting price of a Descending auction, before any discount pool has been applied
+    pub start_price: Option<u128>,
+    /// floor price of a Descending auction, below which the effective minimum bid will not fall
+    pub floor_price: Option<u128>,
+    /// number of discount pools the price steps through between start_price and floor_price
+    pub pool_count: Option<u16>,
+    /// number of seconds each discount pool remains active before stepping down to the next
+    pub interval_seconds: Option<u64>,
+    /// optional price that, once bid, closes the auction immediately
+    pub buy_now_price: Option<u128>,
 }
 
 impl StoreAuctionInfo {
-    /// takes the active auction information and creates a closed auction info struct
+    /// returns true if the given bid meets or exceeds buy_now_price and should close the
+    /// auction immediately
+    pub fn is_buy_now(&self, bid: u128) -> bool {
+        matches!(self.buy_now_price, Some(price) if bid >= price)
+    }
+
+    /// returns the minimum bid that will be accepted right now.  For a Descending auction this
+    /// steps down from start_price to floor_price one discount pool at a time as
+    /// interval_seconds elapse since created_at; for a Sealed auction it is just the fixed
+    /// minimum_bid
+    pub fn current_minimum_bid(&self, now: u64) -> u128 {
+        let (start, floor, pool_count, interval_seconds) = match (
+            self.auction_type,
+            self.start_price,
+            self.floor_price,
+            self.pool_count,
+            self.interval_seconds,
+        ) {
+            (AuctionType::Descending, Some(start), Some(floor), Some(pool_count), Some(interval_seconds))
+                if pool_count > 0 && interval_seconds > 0 && start > floor =>
+            {
+                (start, floor, pool_count, interval_seconds)
+            }
+            _ => return self.minimum_bid,
+        };
+        if now <= self.created_at {
+            return start;
+        }
+        let elapsed = now - self.created_at;
+        let pools_elapsed = (elapsed / interval_seconds).min(u64::from(pool_count));
+        if pools_elapsed == u64::from(pool_count) {
+            return floor;
+        }
+        let step = (start - floor) / u128::from(pool_count);
+        start.saturating_sub(step * u128::from(pools_elapsed)).max(floor)
+    }
+
+    /// takes the active auction information and creates a closed auction info struct, splitting
+    /// the winning bid into protocol fee and net-to-seller amounts when fee_info is supplied
     pub fn to_store_closed_auction_info(
         &self,
         winning_bid: Option<u128>,
         timestamp: u64,
+        fee_info: Option<&FeeInfo>,
+        closed_by: CloseReason,
     ) -> StoreClosedAuctionInfo {
+        let (fee_paid, net_to_seller) = match (winning_bid, fee_info) {
+            (Some(bid), Some(fees)) => {
+                let (fee_paid, net_to_seller) = fees.split(bid);
+                (Some(fee_paid), Some(net_to_seller))
+            }
+            _ => (None, None),
+        };
         StoreClosedAuctionInfo {
             address: self.address.clone(),
             label: self.label.clone(),
@@ -357,6 +646,9 @@ impl StoreAuctionInfo {
             sell_amount: self.sell_amount,
             winning_bid,
             timestamp,
+            fee_paid,
+            net_to_seller,
+            closed_by,
         }
     }
 }
@@ -383,6 +675,14 @@ pub struct ClosedAuctionInfo {
     /// number of decimal places in winning_bid
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bid_decimals: Option<u8>,
+    /// protocol fee charged on the winning bid
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_paid: Option<Uint128>,
+    /// amount the seller received after the protocol fee was deducted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net_to_seller: Option<Uint128>,
+    /// how the auction came to a close
+    pub closed_by: CloseReason,
     /// time the auction closed in seconds since epoch 01/01/1970
     pub timestamp: u64,
 }
@@ -402,6 +702,12 @@ pub struct StoreClosedAuctionInfo {
     pub sell_amount: u128,
     /// winning bid
     pub winning_bid: Option<u128>,
+    /// protocol fee charged on the winning bid
+    pub fee_paid: Option<u128>,
+    /// amount the seller received after the protocol fee was deducted
+    pub net_to_seller: Option<u128>,
+    /// how the auction came to a close
+    pub closed_by: CloseReason,
     /// time the auction closed in seconds since epoch 01/01/1970
     pub timestamp: u64,
 }